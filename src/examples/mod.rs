@@ -3,5 +3,3 @@ pub mod range_lookup;
 mod range_lookup3;
 mod range_lookup_2;
 mod simple_chip;
-mod table;
-mod table2;
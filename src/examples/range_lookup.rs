@@ -2,60 +2,52 @@ use halo2_proofs::{
     circuit::{AssignedCell, Layouter, SimpleFloorPlanner, Value},
     pasta::group::ff::PrimeField,
     plonk::*,
-    poly::Rotation,
 };
 
-use super::table;
+use crate::gadgets::range_check::{RangeCheckChip, RangeCheckConfig};
 
-struct ACell<F: PrimeField>(AssignedCell<Assigned<F>, F>);
+struct ACell<F: PrimeField>(AssignedCell<F, F>);
 
+/// Thin wrapper around [`RangeCheckChip`] checking `NUM` values at once.
 #[derive(Clone, Debug)]
 struct RangeConfig<F: PrimeField, const RANGE: usize, const NUM: usize> {
-    value: Column<Advice>,
-    table: table::LookupTable<F, RANGE>,
-    q_lookup: Selector,
+    config: RangeCheckConfig<F, RANGE>,
 }
 
 impl<F: PrimeField, const RANGE: usize, const NUM: usize> RangeConfig<F, RANGE, NUM> {
     pub fn configure(meta: &mut ConstraintSystem<F>, value: Column<Advice>) -> Self {
-        let q_lookup = meta.complex_selector();
-
-        let table = table::LookupTable::<F, RANGE>::configure(meta);
-
-        meta.lookup(|meta| {
-            let q_lookup = meta.query_selector(q_lookup);
-            let v = meta.query_advice(value, Rotation::cur());
-            vec![(q_lookup * v, table.table)]
-        });
+        RangeConfig {
+            config: RangeCheckChip::configure(meta, value),
+        }
+    }
 
+    /// Configures a range check against the union of several disjoint,
+    /// inclusive ranges below `RANGE`, e.g. `[a,b] ∪ [c,d]`.
+    pub fn configure_multi_range(
+        meta: &mut ConstraintSystem<F>,
+        value: Column<Advice>,
+        ranges: &[(usize, usize)],
+    ) -> Self {
         RangeConfig {
-            value,
-            table,
-            q_lookup,
+            config: RangeCheckChip::configure_ranges(meta, value, ranges),
         }
     }
 
+    fn load_table(&self, layouter: impl Layouter<F>) -> Result<(), Error> {
+        RangeCheckChip::construct(self.config.clone()).load_table(layouter)
+    }
+
     fn assign(
         &self,
         mut layouter: impl Layouter<F>,
         value: [Value<Assigned<F>>; NUM],
     ) -> Result<ACell<F>, Error> {
-        layouter.assign_region(
-            || "value to check",
-            |mut region| {
-                self.q_lookup.enable(&mut region, 0)?;
-                let mut cell = region
-                    .assign_advice(|| "value", self.value, 0, || value[0])
-                    .map(ACell);
-                for (i, _) in value.iter().enumerate().skip(1) {
-                    self.q_lookup.enable(&mut region, i)?;
-                    cell = region
-                        .assign_advice(|| "value", self.value, i, || value[i])
-                        .map(ACell);
-                }
-                cell
-            },
-        )
+        let chip = RangeCheckChip::construct(self.config.clone());
+        let mut cell = chip.assign(layouter.namespace(|| "value"), value[0].evaluate())?;
+        for v in value.iter().skip(1) {
+            cell = chip.assign(layouter.namespace(|| "value"), v.evaluate())?;
+        }
+        Ok(ACell(cell))
     }
 }
 
@@ -94,7 +86,45 @@ impl<F: PrimeField, const RANGE: usize, const NUM: usize> Circuit<F> for MyCircu
         config: Self::Config,
         mut layouter: impl Layouter<F>,
     ) -> Result<(), Error> {
-        config.table.load(layouter.namespace(|| "lookup col"))?;
+        config.load_table(layouter.namespace(|| "lookup col"))?;
+        config.assign(layouter.namespace(|| "range check"), self.value)?;
+        Ok(())
+    }
+}
+
+/// Allowed values for [`MultiRangeCircuit`]: `[2,5] ∪ [10,13]`.
+#[cfg(test)]
+const MULTI_RANGES: [(usize, usize); 2] = [(2, 5), (10, 13)];
+
+#[cfg(test)]
+#[derive(Debug)]
+struct MultiRangeCircuit<F: PrimeField, const NUM: usize> {
+    value: [Value<Assigned<F>>; NUM],
+}
+
+#[cfg(test)]
+impl<F: PrimeField, const NUM: usize> Circuit<F> for MultiRangeCircuit<F, NUM> {
+    type Config = RangeConfig<F, 16, NUM>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        MultiRangeCircuit {
+            value: [Value::unknown(); NUM],
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = meta.advice_column();
+
+        RangeConfig::<F, 16, NUM>::configure_multi_range(meta, advice, &MULTI_RANGES)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        config.load_table(layouter.namespace(|| "lookup col"))?;
         config.assign(layouter.namespace(|| "range check"), self.value)?;
         Ok(())
     }
@@ -130,6 +160,26 @@ mod test {
         assert!(prover.verify().is_err());
     }
 
+    #[test]
+    fn multi_range_lookup() {
+        use halo2_proofs::{dev::MockProver, pasta::Fp};
+        let k = 5;
+
+        // One value from each range.
+        let circuit = MultiRangeCircuit::<Fp, 2> {
+            value: [3, 12].map(|v| Value::known(Assigned::from(Fp::from(v)))),
+        };
+        let prover = MockProver::run(k, &circuit, vec![]).unwrap();
+        prover.assert_satisfied();
+
+        // 7 lies in the gap between the two ranges.
+        let circuit = MultiRangeCircuit::<Fp, 2> {
+            value: [3, 7].map(|v| Value::known(Assigned::from(Fp::from(v)))),
+        };
+        let prover = MockProver::run(k, &circuit, vec![]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
     #[cfg(feature = "dev-graph")]
     #[test]
     fn lookup_example_graph() {
@@ -19,6 +19,12 @@ use halo2_proofs::{
 /// |         |         |    0    |  RANGE  |  RANGE  |
 /// - cur_a ∈ t1
 /// - next_b ∈ t2
+///
+/// Both columns are looked up as one `(cur_a, next_b)` pair against rows of
+/// `(t1, t2)`, so each `a` must also equal the `b` below it. That is a
+/// cross-row, two-column lookup, so unlike the other range-lookup examples
+/// this one does not wrap [`crate::gadgets::range_check`], whose chips check
+/// a single row.
 
 #[derive(Clone, Debug)]
 struct RangeLookupConfig {
@@ -162,6 +168,38 @@ mod test {
         prover.assert_satisfied();
     }
 
+    #[allow(unused)]
+    fn circuit_from(a: &[u64], b: &[u64]) -> RangeLookupCircuit<Fp> {
+        let a = a.iter().map(|v| Value::known(Fp::from(*v))).collect();
+        let b = b.iter().map(|v| Value::known(Fp::from(*v))).collect();
+
+        RangeLookupCircuit::<Fp> { a, b }
+    }
+
+    #[test]
+    fn test_range_lookup_a_out_of_range() {
+        // cur_a = 12 on the last row is not in table_1.
+        let circuit = circuit_from(&[0, 1, 12], &[0, 0, 1, 2]);
+        let prover = halo2_proofs::dev::MockProver::run(5, &circuit, vec![]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn test_range_lookup_next_b_out_of_range() {
+        // Every b is checked one row below its a, so b[3] = 15 is looked up
+        // from row 2 and is not in table_2.
+        let circuit = circuit_from(&[0, 1, 2], &[0, 0, 1, 15]);
+        let prover = halo2_proofs::dev::MockProver::run(5, &circuit, vec![]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn test_range_lookup_empty() {
+        let circuit = circuit_from(&[], &[]);
+        let prover = halo2_proofs::dev::MockProver::run(5, &circuit, vec![]).unwrap();
+        prover.assert_satisfied();
+    }
+
     #[cfg(feature = "dev-graph")]
     #[test]
     fn draw_range_lookup() {
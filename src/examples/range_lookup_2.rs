@@ -1,4 +1,4 @@
-use super::table2::*;
+use crate::gadgets::range_check::{TaggedRangeCheckChip, TaggedRangeCheckConfig};
 /// This helper uses a lookup table to check that the value witnessed in a given cell is
 /// within a given range.
 ///
@@ -19,14 +19,12 @@ use super::table2::*;
 /// |  ...  |  ...   |   ...     |       3      |      7      |
 /// |  ...  |  ...   |   ...     |       4      |      8      |
 /// |  ...  |  ...   |   ...     |      ...     |     ...     |
-use halo2_proofs::{circuit::*, pasta::group::ff::PrimeField, plonk::*, poly::Rotation};
+use halo2_proofs::{circuit::*, pasta::group::ff::PrimeField, plonk::*};
 
+/// Thin wrapper around [`TaggedRangeCheckChip`].
 #[derive(Debug, Clone)]
 struct RangeCheckConfig<F: PrimeField, const NUM_BITS: usize, const RANGE: usize> {
-    value: Column<Advice>,
-    bit: Column<Advice>,
-    q_lookup: Selector,
-    table: RangeCheckTable<F, NUM_BITS, RANGE>,
+    config: TaggedRangeCheckConfig<F, NUM_BITS, RANGE>,
 }
 
 impl<F: PrimeField, const NUM_BITS: usize, const RANGE: usize>
@@ -35,51 +33,36 @@ impl<F: PrimeField, const NUM_BITS: usize, const RANGE: usize>
     pub fn configure(meta: &mut ConstraintSystem<F>) -> Self {
         let value = meta.advice_column();
         let bit = meta.advice_column();
-        let q_lookup = meta.complex_selector();
-        let table = RangeCheckTable::<F, NUM_BITS, RANGE>::configure(meta);
-
-        meta.lookup(|meta| {
-            let default_value = Expression::Constant(F::ZERO);
-            let default_bit = Expression::Constant(F::ONE);
-            let value = meta.query_advice(value, Rotation::cur());
-            let bit = meta.query_advice(bit, Rotation::cur());
-            let q_lookup = meta.query_selector(q_lookup);
-            let non_q = Expression::Constant(F::ONE) - q_lookup.clone();
-
-            let v = value * q_lookup.clone() + non_q.clone() * default_value.clone();
-            let b = bit * q_lookup + non_q * default_bit;
-            vec![(b, table.n_bits), (v, table.value)]
-        });
 
         RangeCheckConfig {
-            value,
-            bit,
-            q_lookup,
-            table,
+            config: TaggedRangeCheckChip::configure(meta, value, bit),
         }
     }
 
     fn assign_table(&self, layouter: impl Layouter<F>) -> Result<(), Error> {
-        self.table.load(layouter)
+        TaggedRangeCheckChip::construct(self.config.clone()).load_table(layouter)
     }
 
     fn assign(
         &self,
         mut layouter: impl Layouter<F>,
         values: &[Value<Assigned<F>>],
-        bits: Vec<Value<F>>,
+        bits: &[u8],
     ) -> Result<(), Error> {
-        layouter.assign_region(
-            || "bit && vlaue region",
-            |mut region| {
-                for i in 0..NUM_BITS {
-                    self.q_lookup.enable(&mut region, i)?;
-                    region.assign_advice(|| "value", self.value, i, || values[i])?;
-                    region.assign_advice(|| "bit", self.bit, i, || bits[i])?;
-                }
-                Ok(())
-            },
-        )
+        // Every value needs its own bit tag, otherwise it would go unchecked.
+        if values.len() != bits.len() {
+            return Err(Error::Synthesis);
+        }
+
+        let chip = TaggedRangeCheckChip::construct(self.config.clone());
+        for (value, bit) in values.iter().zip(bits) {
+            chip.assign(
+                layouter.namespace(|| "bit && vlaue"),
+                value.evaluate(),
+                *bit as usize,
+            )?;
+        }
+        Ok(())
     }
 }
 
@@ -106,12 +89,7 @@ impl<F: PrimeField, const NUM_BITS: usize, const RANGE: usize> Circuit<F>
         mut layouter: impl Layouter<F>,
     ) -> Result<(), Error> {
         config.assign_table(layouter.namespace(|| "table"))?;
-        let bits = self
-            .bits
-            .iter()
-            .map(|x| Value::known(F::from(*x as u64)))
-            .collect::<Vec<Value<F>>>();
-        config.assign(layouter.namespace(|| "value"), &self.values, bits)?;
+        config.assign(layouter.namespace(|| "value"), &self.values, &self.bits)?;
         Ok(())
     }
 }
@@ -144,6 +122,14 @@ mod tests {
         prover.assert_satisfied();
     }
 
+    #[test]
+    fn test_rangecheck_lookup_missing_bits() {
+        let mut circuit = circuit();
+        circuit.bits.pop();
+        let result = MockProver::run(5, &circuit, vec![]);
+        assert!(matches!(result, Err(Error::Synthesis)));
+    }
+
     #[cfg(feature = "dev-graph")]
     #[test]
     fn plot_multi_cols_rangecheck_lookup() {
@@ -11,15 +11,33 @@ use halo2_proofs::{
     poly::Rotation,
 };
 
+// `SimpleChip::assign` computes:
 // d = a^2  * b^2  *c
 //  e = c + d
 // out = e^ 3
+//
+// `mul`, `add`, `sub` and `cube` expose each gate on its own, so other
+// expressions can be built in `synthesize`.
+//
+// mul / add / sub (s_mul, s_add, s_sub):
+// | a0  | a1  | selector |
+// |-----|-----|----------|
+// | lhs | rhs |    1     |
+// | out |     |    0     |
+// out = lhs * rhs, lhs + rhs or lhs - rhs
+//
+// cube (s_cub):
+// | a0  | a1  | s_cub |
+// |-----|-----|-------|
+// | lhs | out |   1   |
+// out = lhs^3
 #[derive(Clone, Debug)]
 struct SimpleConfig {
     advice: [Column<Advice>; 2],
     instance: Column<Instance>,
     s_mul: Selector,
     s_add: Selector,
+    s_sub: Selector,
     s_cub: Selector,
 }
 
@@ -54,6 +72,7 @@ impl<F: Field> SimpleChip<F> {
 
         let s_mul = meta.selector();
         let s_add = meta.selector();
+        let s_sub = meta.selector();
         let s_cub = meta.selector();
         meta.create_gate("mul", |meta| {
             //to implement multiplication,need three advice cells and a selector cell
@@ -78,6 +97,19 @@ impl<F: Field> SimpleChip<F> {
             Constraints::with_selector(s_add, [lhs + rhs - out])
         });
 
+        meta.create_gate("sub", |meta| {
+            // | a0  | a1  | s_sub |
+            // |-----|-----|-------|
+            // | lhs | rhs | s_sub |
+            // | out |     |       |
+            let lhs = meta.query_advice(advices[0], Rotation::cur());
+            let rhs = meta.query_advice(advices[1], Rotation::cur());
+            let out = meta.query_advice(advices[0], Rotation::next());
+            let s_sub = meta.query_selector(s_sub);
+
+            Constraints::with_selector(s_sub, [lhs - rhs - out])
+        });
+
         meta.create_gate("cub", |meta| {
             let lhs = meta.query_advice(advices[0], Rotation::cur());
             let out = meta.query_advice(advices[1], Rotation::cur());
@@ -91,6 +123,7 @@ impl<F: Field> SimpleChip<F> {
             instance,
             s_mul,
             s_add,
+            s_sub,
             s_cub,
         }
     }
@@ -102,30 +135,23 @@ impl<F: Field> SimpleChip<F> {
         b: Value<F>,
         c: F,
     ) -> Result<Number<F>, Error> {
-        let cells = layouter
-            .assign_region(
-                || "load private inputs",
-                |mut region| {
-                    let a_cell = region
-                        .assign_advice(|| "private input a", self.config.advice[0], 0, || a)
-                        .map(Number)?;
+        let cells = layouter.assign_region(
+            || "load private inputs",
+            |mut region| {
+                let a_cell = region
+                    .assign_advice(|| "private input a", self.config.advice[0], 0, || a)
+                    .map(Number)?;
 
-                    let b_cell = region
-                        .assign_advice(|| "private input b", self.config.advice[0], 1, || b)
-                        .map(Number)?;
+                let b_cell = region
+                    .assign_advice(|| "private input b", self.config.advice[0], 1, || b)
+                    .map(Number)?;
 
-                    let c_cell = region
-                        .assign_advice_from_constant(
-                            || "private input c",
-                            self.config.advice[0],
-                            2,
-                            c,
-                        )
-                        .map(Number)?;
-                    Ok((a_cell, b_cell, c_cell))
-                },
-            )
-            .unwrap();
+                let c_cell = region
+                    .assign_advice_from_constant(|| "private input c", self.config.advice[0], 2, c)
+                    .map(Number)?;
+                Ok((a_cell, b_cell, c_cell))
+            },
+        )?;
 
         layouter.assign_region(
             || "load witness",
@@ -194,6 +220,127 @@ impl<F: Field> SimpleChip<F> {
         )
     }
 
+    pub fn load_private(
+        &self,
+        mut layouter: impl Layouter<F>,
+        value: Value<F>,
+    ) -> Result<Number<F>, Error> {
+        layouter.assign_region(
+            || "load private",
+            |mut region| {
+                region
+                    .assign_advice(|| "private input", self.config.advice[0], 0, || value)
+                    .map(Number)
+            },
+        )
+    }
+
+    pub fn load_constant(
+        &self,
+        mut layouter: impl Layouter<F>,
+        constant: F,
+    ) -> Result<Number<F>, Error> {
+        layouter.assign_region(
+            || "load constant",
+            |mut region| {
+                region
+                    .assign_advice_from_constant(
+                        || "constant input",
+                        self.config.advice[0],
+                        0,
+                        constant,
+                    )
+                    .map(Number)
+            },
+        )
+    }
+
+    /// out = a * b, using the `mul` gate.
+    pub fn mul(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Number<F>,
+        b: Number<F>,
+    ) -> Result<Number<F>, Error> {
+        let config = &self.config;
+        layouter.assign_region(
+            || "mul",
+            |mut region| {
+                config.s_mul.enable(&mut region, 0)?;
+                a.0.copy_advice(|| "lhs", &mut region, config.advice[0], 0)?;
+                b.0.copy_advice(|| "rhs", &mut region, config.advice[1], 0)?;
+
+                let value = a.0.value().copied() * b.0.value().copied();
+                region
+                    .assign_advice(|| "out=lhs*rhs", config.advice[0], 1, || value)
+                    .map(Number)
+            },
+        )
+    }
+
+    /// out = a + b, using the `add` gate.
+    pub fn add(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Number<F>,
+        b: Number<F>,
+    ) -> Result<Number<F>, Error> {
+        let config = &self.config;
+        layouter.assign_region(
+            || "add",
+            |mut region| {
+                config.s_add.enable(&mut region, 0)?;
+                a.0.copy_advice(|| "lhs", &mut region, config.advice[0], 0)?;
+                b.0.copy_advice(|| "rhs", &mut region, config.advice[1], 0)?;
+
+                let value = a.0.value().copied() + b.0.value().copied();
+                region
+                    .assign_advice(|| "out=lhs+rhs", config.advice[0], 1, || value)
+                    .map(Number)
+            },
+        )
+    }
+
+    /// out = a - b, using the `sub` gate.
+    pub fn sub(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Number<F>,
+        b: Number<F>,
+    ) -> Result<Number<F>, Error> {
+        let config = &self.config;
+        layouter.assign_region(
+            || "sub",
+            |mut region| {
+                config.s_sub.enable(&mut region, 0)?;
+                a.0.copy_advice(|| "lhs", &mut region, config.advice[0], 0)?;
+                b.0.copy_advice(|| "rhs", &mut region, config.advice[1], 0)?;
+
+                let value = a.0.value().copied() - b.0.value().copied();
+                region
+                    .assign_advice(|| "out=lhs-rhs", config.advice[0], 1, || value)
+                    .map(Number)
+            },
+        )
+    }
+
+    /// out = a^3, using the `cub` gate.
+    pub fn cube(&self, mut layouter: impl Layouter<F>, a: Number<F>) -> Result<Number<F>, Error> {
+        let config = &self.config;
+        layouter.assign_region(
+            || "cube",
+            |mut region| {
+                config.s_cub.enable(&mut region, 0)?;
+                a.0.copy_advice(|| "lhs", &mut region, config.advice[0], 0)?;
+
+                let value = a.0.value().copied() * a.0.value().copied() * a.0.value().copied();
+                region
+                    .assign_advice(|| "out=lhs^3", config.advice[1], 0, || value)
+                    .map(Number)
+            },
+        )
+    }
+
     pub fn expose_public(
         &self,
         mut layouter: impl Layouter<F>,
@@ -287,6 +434,161 @@ mod tests {
         // ANCHOR_END: test-circuit
     }
 
+    #[test]
+    fn test_simple_chip_k_too_small() {
+        // At k = 3 only rows 0 and 1 are usable, so "load private inputs"
+        // fails when it assigns c at row 2.
+        let (circuit, out) = circuit();
+        let result = MockProver::run(3, &circuit, vec![vec![out]]);
+        assert!(matches!(result, Err(Error::NotEnoughRowsAvailable { .. })));
+    }
+
+    /// Builds `(a + b) * a` from the chip's primitives.
+    #[derive(Default)]
+    struct ExprCircuit<F: Field> {
+        a: Value<F>,
+        b: Value<F>,
+    }
+
+    impl<F: Field> Circuit<F> for ExprCircuit<F> {
+        type Config = SimpleConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            SimpleChip::configure(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let chip = SimpleChip::construct(config);
+            let a = chip.load_private(layouter.namespace(|| "load a"), self.a)?;
+            let b = chip.load_private(layouter.namespace(|| "load b"), self.b)?;
+
+            let sum = chip.add(layouter.namespace(|| "a+b"), a.clone(), b)?;
+            let out = chip.mul(layouter.namespace(|| "(a+b)*a"), sum, a)?;
+            chip.expose_public(layouter.namespace(|| "expose"), out, 0)
+        }
+    }
+
+    #[test]
+    fn test_simple_chip_primitives() {
+        let k = 5;
+        let a = Fp::from(2);
+        let b = Fp::from(3);
+        let circuit = ExprCircuit {
+            a: Value::known(a),
+            b: Value::known(b),
+        };
+
+        let mut public_inputs = vec![(a + b) * a];
+        let prover = MockProver::run(k, &circuit, vec![public_inputs.clone()]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+
+        public_inputs[0] += Fp::one();
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    /// Builds `(a + 2)^3` from the chip's primitives.
+    #[derive(Default)]
+    struct CubeCircuit<F: Field> {
+        a: Value<F>,
+    }
+
+    impl<F: Field> Circuit<F> for CubeCircuit<F> {
+        type Config = SimpleConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            SimpleChip::configure(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let chip = SimpleChip::construct(config);
+            let a = chip.load_private(layouter.namespace(|| "load a"), self.a)?;
+            let two = chip.load_constant(layouter.namespace(|| "load 2"), F::ONE.double())?;
+
+            let sum = chip.add(layouter.namespace(|| "a+2"), a, two)?;
+            let out = chip.cube(layouter.namespace(|| "(a+2)^3"), sum)?;
+            chip.expose_public(layouter.namespace(|| "expose"), out, 0)
+        }
+    }
+
+    #[test]
+    fn test_simple_chip_cube() {
+        let k = 5;
+        let a = Fp::from(3);
+        let circuit = CubeCircuit { a: Value::known(a) };
+
+        let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(125)]]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+
+        let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(27)]]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[derive(Default)]
+    struct SubCircuit<F: Field> {
+        a: Value<F>,
+        b: Value<F>,
+    }
+
+    impl<F: Field> Circuit<F> for SubCircuit<F> {
+        type Config = SimpleConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            SimpleChip::configure(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let chip = SimpleChip::construct(config);
+            let a = chip.load_private(layouter.namespace(|| "load a"), self.a)?;
+            let b = chip.load_private(layouter.namespace(|| "load b"), self.b)?;
+
+            let out = chip.sub(layouter.namespace(|| "a-b"), a, b)?;
+            chip.expose_public(layouter.namespace(|| "expose"), out, 0)
+        }
+    }
+
+    #[test]
+    fn test_simple_chip_sub() {
+        let k = 5;
+        let circuit = SubCircuit {
+            a: Value::known(Fp::from(5)),
+            b: Value::known(Fp::from(3)),
+        };
+
+        let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(2)]]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+
+        let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(8)]]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
     #[cfg(feature = "dev-graph")]
     #[test]
     fn plot_chip_circuit() {
@@ -0,0 +1,275 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Layouter},
+    plonk::{Advice, Column, ConstraintSystem, Constraints, Error, Selector},
+    poly::Rotation,
+};
+
+/// | a0  | a1  | s_add |
+/// |-----|-----|-------|
+/// | lhs | rhs |   1   |
+/// | out |     |   0   |
+#[derive(Clone, Debug)]
+pub struct AddConfig {
+    pub advice: [Column<Advice>; 2],
+    pub s_add: Selector,
+}
+
+#[derive(Clone, Debug)]
+pub struct AddChip<F: Field> {
+    config: AddConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field> AddChip<F> {
+    pub fn construct(config: AddConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn configure(meta: &mut ConstraintSystem<F>, advice: [Column<Advice>; 2]) -> AddConfig {
+        for column in &advice {
+            meta.enable_equality(*column);
+        }
+        let s_add = meta.selector();
+
+        meta.create_gate("add", |meta| {
+            let lhs = meta.query_advice(advice[0], Rotation::cur());
+            let rhs = meta.query_advice(advice[1], Rotation::cur());
+            let out = meta.query_advice(advice[0], Rotation::next());
+            let s_add = meta.query_selector(s_add);
+
+            Constraints::with_selector(s_add, [lhs + rhs - out])
+        });
+
+        AddConfig { advice, s_add }
+    }
+
+    /// out = a + b
+    pub fn add(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &AssignedCell<F, F>,
+        b: &AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        let config = &self.config;
+        layouter.assign_region(
+            || "add",
+            |mut region| {
+                config.s_add.enable(&mut region, 0)?;
+                a.copy_advice(|| "lhs", &mut region, config.advice[0], 0)?;
+                b.copy_advice(|| "rhs", &mut region, config.advice[1], 0)?;
+
+                let value = a.value().copied() + b.value();
+                region.assign_advice(|| "lhs + rhs", config.advice[0], 1, || value)
+            },
+        )
+    }
+}
+
+/// | a0  | a1  | s_mul |
+/// |-----|-----|-------|
+/// | lhs | rhs |   1   |
+/// | out |     |   0   |
+///
+/// Same layout as [`AddConfig`], so the two chips can share columns.
+#[derive(Clone, Debug)]
+pub struct MulConfig {
+    pub advice: [Column<Advice>; 2],
+    pub s_mul: Selector,
+}
+
+#[derive(Clone, Debug)]
+pub struct MulChip<F: Field> {
+    config: MulConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field> MulChip<F> {
+    pub fn construct(config: MulConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn configure(meta: &mut ConstraintSystem<F>, advice: [Column<Advice>; 2]) -> MulConfig {
+        for column in &advice {
+            meta.enable_equality(*column);
+        }
+        let s_mul = meta.selector();
+
+        meta.create_gate("mul", |meta| {
+            let lhs = meta.query_advice(advice[0], Rotation::cur());
+            let rhs = meta.query_advice(advice[1], Rotation::cur());
+            let out = meta.query_advice(advice[0], Rotation::next());
+            let s_mul = meta.query_selector(s_mul);
+
+            Constraints::with_selector(s_mul, [lhs * rhs - out])
+        });
+
+        MulConfig { advice, s_mul }
+    }
+
+    /// out = a * b
+    pub fn mul(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &AssignedCell<F, F>,
+        b: &AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        let config = &self.config;
+        layouter.assign_region(
+            || "mul",
+            |mut region| {
+                config.s_mul.enable(&mut region, 0)?;
+                a.copy_advice(|| "lhs", &mut region, config.advice[0], 0)?;
+                b.copy_advice(|| "rhs", &mut region, config.advice[1], 0)?;
+
+                let value = a.value().copied() * b.value();
+                region.assign_advice(|| "lhs * rhs", config.advice[0], 1, || value)
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use halo2_proofs::{
+        circuit::{SimpleFloorPlanner, Value},
+        dev::MockProver,
+        pasta::Fp,
+        plonk::{Circuit, Instance},
+    };
+    use rand_core::OsRng;
+
+    #[derive(Default)]
+    struct AddCircuit<F: Field> {
+        a: Value<F>,
+        b: Value<F>,
+    }
+
+    impl<F: Field> Circuit<F> for AddCircuit<F> {
+        type Config = (AddConfig, Column<Instance>);
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let advice = [meta.advice_column(), meta.advice_column()];
+            let instance = meta.instance_column();
+            meta.enable_equality(instance);
+
+            (AddChip::configure(meta, advice), instance)
+        }
+
+        fn synthesize(
+            &self,
+            (config, instance): Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let (a, b) = layouter.assign_region(
+                || "load inputs",
+                |mut region| {
+                    let a = region.assign_advice(|| "a", config.advice[0], 0, || self.a)?;
+                    let b = region.assign_advice(|| "b", config.advice[1], 0, || self.b)?;
+                    Ok((a, b))
+                },
+            )?;
+
+            let chip = AddChip::construct(config);
+            let out = chip.add(layouter.namespace(|| "a + b"), &a, &b)?;
+            layouter.constrain_instance(out.cell(), instance, 0)
+        }
+    }
+
+    #[test]
+    fn add_chip() {
+        for _ in 0..4 {
+            let a = Fp::random(OsRng);
+            let b = Fp::random(OsRng);
+            let circuit = AddCircuit {
+                a: Value::known(a),
+                b: Value::known(b),
+            };
+
+            let prover = MockProver::run(4, &circuit, vec![vec![a + b]]).unwrap();
+            prover.assert_satisfied();
+
+            let prover = MockProver::run(4, &circuit, vec![vec![a + b + Fp::one()]]).unwrap();
+            assert!(prover.verify().is_err());
+        }
+    }
+
+    #[derive(Default)]
+    struct MulAddCircuit<F: Field> {
+        a: Value<F>,
+        b: Value<F>,
+        c: Value<F>,
+    }
+
+    impl<F: Field> Circuit<F> for MulAddCircuit<F> {
+        type Config = (MulConfig, AddConfig, Column<Instance>);
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let advice = [meta.advice_column(), meta.advice_column()];
+            let instance = meta.instance_column();
+            meta.enable_equality(instance);
+
+            (
+                MulChip::configure(meta, advice),
+                AddChip::configure(meta, advice),
+                instance,
+            )
+        }
+
+        fn synthesize(
+            &self,
+            (mul_config, add_config, instance): Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let (a, b, c) = layouter.assign_region(
+                || "load inputs",
+                |mut region| {
+                    let a = region.assign_advice(|| "a", mul_config.advice[0], 0, || self.a)?;
+                    let b = region.assign_advice(|| "b", mul_config.advice[1], 0, || self.b)?;
+                    let c = region.assign_advice(|| "c", mul_config.advice[0], 1, || self.c)?;
+                    Ok((a, b, c))
+                },
+            )?;
+
+            let ab = MulChip::construct(mul_config).mul(layouter.namespace(|| "a * b"), &a, &b)?;
+            let out =
+                AddChip::construct(add_config).add(layouter.namespace(|| "ab + c"), &ab, &c)?;
+            layouter.constrain_instance(out.cell(), instance, 0)
+        }
+    }
+
+    #[test]
+    fn mul_then_add() {
+        let (a, b, c) = (Fp::from(6), Fp::from(7), Fp::from(8));
+        let circuit = MulAddCircuit {
+            a: Value::known(a),
+            b: Value::known(b),
+            c: Value::known(c),
+        };
+
+        let prover = MockProver::run(4, &circuit, vec![vec![a * b + c]]).unwrap();
+        prover.assert_satisfied();
+
+        let prover = MockProver::run(4, &circuit, vec![vec![a * (b + c)]]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+}
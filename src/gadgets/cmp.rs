@@ -0,0 +1,249 @@
+use std::{cmp::Ordering, marker::PhantomData};
+
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter},
+    pasta::group::ff::PrimeField,
+    plonk::{Advice, Column, ConstraintSystem, Constraints, Error, Expression, Selector},
+    poly::Rotation,
+};
+
+use super::decompose::{decompose, DecomposeConfig};
+
+/// Computes `lt = (lhs < rhs)` for inputs below `2^(8 * N_BYTES)`.
+///
+/// | lhs | rhs | diff | lt | q_lt |
+/// |-----|-----|------|----|------|
+/// |  a  |  b  |  d   | lt |  1   |
+///
+/// with `d = a - b + lt * 2^(8 * N_BYTES)`. `a`, `b` and `d` are each
+/// decomposed into `8 * N_BYTES` bits in their own regions: the first two
+/// enforce the input bound, and the last only succeeds when `lt` is the
+/// honest comparison result.
+#[derive(Clone, Debug)]
+pub struct LessThanConfig<F: PrimeField, const N_BYTES: usize> {
+    pub lhs: Column<Advice>,
+    pub rhs: Column<Advice>,
+    pub diff: Column<Advice>,
+    pub lt: Column<Advice>,
+    pub q_lt: Selector,
+    pub decompose: DecomposeConfig,
+    _marker: PhantomData<F>,
+}
+
+#[derive(Clone, Debug)]
+pub struct LessThanChip<F: PrimeField, const N_BYTES: usize> {
+    config: LessThanConfig<F, N_BYTES>,
+}
+
+impl<F: PrimeField, const N_BYTES: usize> LessThanChip<F, N_BYTES> {
+    pub fn construct(config: LessThanConfig<F, N_BYTES>) -> Self {
+        Self { config }
+    }
+
+    pub fn configure(meta: &mut ConstraintSystem<F>) -> LessThanConfig<F, N_BYTES> {
+        assert!(
+            8 * N_BYTES < F::NUM_BITS as usize,
+            "N_BYTES is too large for the field"
+        );
+
+        let lhs = meta.advice_column();
+        let rhs = meta.advice_column();
+        let diff = meta.advice_column();
+        let lt = meta.advice_column();
+        let bit = meta.advice_column();
+        let q_lt = meta.selector();
+
+        meta.enable_equality(lhs);
+        meta.enable_equality(rhs);
+        meta.enable_equality(lt);
+
+        // diff doubles as the running sum of its own decomposition.
+        let decompose = DecomposeConfig::configure(meta, diff, bit);
+
+        meta.create_gate("less than", |meta| {
+            let q_lt = meta.query_selector(q_lt);
+            let lhs = meta.query_advice(lhs, Rotation::cur());
+            let rhs = meta.query_advice(rhs, Rotation::cur());
+            let diff = meta.query_advice(diff, Rotation::cur());
+            let lt = meta.query_advice(lt, Rotation::cur());
+            let one = Expression::Constant(F::ONE);
+            let range = Expression::Constant(Self::range());
+
+            Constraints::with_selector(
+                q_lt,
+                [
+                    ("lt is boolean", lt.clone() * (one - lt.clone())),
+                    (
+                        "diff = lhs - rhs + lt * range",
+                        lhs - rhs + lt * range - diff,
+                    ),
+                ],
+            )
+        });
+
+        LessThanConfig {
+            lhs,
+            rhs,
+            diff,
+            lt,
+            q_lt,
+            decompose,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Range-checks `lhs` and `rhs` and returns a boolean cell that is 1 iff
+    /// `lhs < rhs`.
+    pub fn assign(
+        &self,
+        mut layouter: impl Layouter<F>,
+        lhs: &AssignedCell<F, F>,
+        rhs: &AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        let config = &self.config;
+
+        let lt = lhs
+            .value()
+            .zip(rhs.value())
+            .map(|(lhs, rhs)| F::from(u64::from(lt_native(lhs, rhs))));
+        let diff = lhs
+            .value()
+            .zip(rhs.value())
+            .zip(lt)
+            .map(|((lhs, rhs), lt)| *lhs - *rhs + lt * Self::range());
+
+        let (lhs_checked, _) = decompose(
+            &config.decompose,
+            layouter.namespace(|| "decompose lhs"),
+            lhs.value().copied(),
+            8 * N_BYTES,
+        )?;
+        let (rhs_checked, _) = decompose(
+            &config.decompose,
+            layouter.namespace(|| "decompose rhs"),
+            rhs.value().copied(),
+            8 * N_BYTES,
+        )?;
+        let (diff, _) = decompose(
+            &config.decompose,
+            layouter.namespace(|| "decompose diff"),
+            diff,
+            8 * N_BYTES,
+        )?;
+
+        layouter.assign_region(
+            || "less than",
+            |mut region| {
+                config.q_lt.enable(&mut region, 0)?;
+                let lhs = lhs.copy_advice(|| "lhs", &mut region, config.lhs, 0)?;
+                region.constrain_equal(lhs.cell(), lhs_checked.cell())?;
+                let rhs = rhs.copy_advice(|| "rhs", &mut region, config.rhs, 0)?;
+                region.constrain_equal(rhs.cell(), rhs_checked.cell())?;
+                diff.copy_advice(|| "diff", &mut region, config.diff, 0)?;
+                region.assign_advice(|| "lt", config.lt, 0, || lt)
+            },
+        )
+    }
+
+    /// `2^(8 * N_BYTES)`
+    fn range() -> F {
+        F::from(2).pow_vartime([8 * N_BYTES as u64])
+    }
+}
+
+/// Compares two field elements as integers, reading `to_repr` as
+/// little-endian.
+fn lt_native<F: PrimeField>(lhs: &F, rhs: &F) -> bool {
+    let lhs = lhs.to_repr();
+    let rhs = rhs.to_repr();
+    lhs.as_ref().iter().rev().cmp(rhs.as_ref().iter().rev()) == Ordering::Less
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use halo2_proofs::{
+        circuit::{SimpleFloorPlanner, Value},
+        dev::MockProver,
+        pasta::Fp,
+        plonk::{Circuit, Instance},
+    };
+
+    #[derive(Default)]
+    struct LessThanCircuit<F: PrimeField> {
+        lhs: Value<F>,
+        rhs: Value<F>,
+    }
+
+    impl<F: PrimeField> Circuit<F> for LessThanCircuit<F> {
+        type Config = (LessThanConfig<F, 1>, Column<Instance>);
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let instance = meta.instance_column();
+            meta.enable_equality(instance);
+
+            (LessThanChip::configure(meta), instance)
+        }
+
+        fn synthesize(
+            &self,
+            (config, instance): Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let (lhs, rhs) = layouter.assign_region(
+                || "load inputs",
+                |mut region| {
+                    let lhs = region.assign_advice(|| "lhs", config.lhs, 0, || self.lhs)?;
+                    let rhs = region.assign_advice(|| "rhs", config.rhs, 0, || self.rhs)?;
+                    Ok((lhs, rhs))
+                },
+            )?;
+
+            let chip = LessThanChip::construct(config);
+            let lt = chip.assign(layouter.namespace(|| "lt"), &lhs, &rhs)?;
+            layouter.constrain_instance(lt.cell(), instance, 0)
+        }
+    }
+
+    fn circuit(lhs: Fp, rhs: Fp) -> LessThanCircuit<Fp> {
+        LessThanCircuit {
+            lhs: Value::known(lhs),
+            rhs: Value::known(rhs),
+        }
+    }
+
+    fn check(lhs: u64, rhs: u64, expected: bool) {
+        let circuit = circuit(Fp::from(lhs), Fp::from(rhs));
+
+        let prover =
+            MockProver::run(6, &circuit, vec![vec![Fp::from(u64::from(expected))]]).unwrap();
+        prover.assert_satisfied();
+
+        let prover =
+            MockProver::run(6, &circuit, vec![vec![Fp::from(u64::from(!expected))]]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn less_than() {
+        check(3, 5, true);
+        check(5, 5, false);
+        check(7, 5, false);
+    }
+
+    #[test]
+    fn less_than_out_of_range_input() {
+        // 259 - 260 + 2^8 = 255 decomposes, so only the input range checks
+        // reject the out-of-range operands.
+        let circuit = circuit(Fp::from(259), Fp::from(260));
+        for lt in [Fp::zero(), Fp::one()] {
+            let prover = MockProver::run(6, &circuit, vec![vec![lt]]).unwrap();
+            assert!(prover.verify().is_err());
+        }
+    }
+}
@@ -0,0 +1,247 @@
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, Value},
+    pasta::group::ff::PrimeField,
+    plonk::{Advice, Column, ConstraintSystem, Constraints, Error, Expression, Selector},
+    poly::Rotation,
+};
+
+/// Little-endian bit decomposition folded from the top bit down:
+/// `acc_i = 2 * acc_{i+1} + b_i`, so `acc_0 = sum(b_i * 2^i)` once `acc_n = 0`.
+///
+/// |  acc  | bit | q_bit | q_end |
+/// |-------|-----|-------|-------|
+/// | value | b_0 |   1   |   0   |
+/// | acc_1 | b_1 |   1   |   0   |
+/// |  ...  | ... |  ...  |  ...  |
+/// | acc_n |     |   0   |   1   |
+#[derive(Clone, Debug)]
+pub struct DecomposeConfig {
+    pub acc: Column<Advice>,
+    pub bit: Column<Advice>,
+    pub q_bit: Selector,
+    pub q_end: Selector,
+}
+
+impl DecomposeConfig {
+    pub fn configure<F: PrimeField>(
+        meta: &mut ConstraintSystem<F>,
+        acc: Column<Advice>,
+        bit: Column<Advice>,
+    ) -> Self {
+        let q_bit = meta.selector();
+        let q_end = meta.selector();
+
+        meta.enable_equality(acc);
+        meta.enable_equality(bit);
+
+        meta.create_gate("bit decomposition", |meta| {
+            let q_bit = meta.query_selector(q_bit);
+            let acc_next = meta.query_advice(acc, Rotation::next());
+            let acc = meta.query_advice(acc, Rotation::cur());
+            let bit = meta.query_advice(bit, Rotation::cur());
+            let one = Expression::Constant(F::ONE);
+            let two = Expression::Constant(F::from(2));
+
+            Constraints::with_selector(
+                q_bit,
+                [
+                    ("bit is boolean", bit.clone() * (one - bit.clone())),
+                    ("acc = 2 * acc_next + bit", acc - acc_next * two - bit),
+                ],
+            )
+        });
+
+        meta.create_gate("decomposition end", |meta| {
+            let q_end = meta.query_selector(q_end);
+            let acc = meta.query_advice(acc, Rotation::cur());
+
+            Constraints::with_selector(q_end, [acc])
+        });
+
+        DecomposeConfig {
+            acc,
+            bit,
+            q_bit,
+            q_end,
+        }
+    }
+}
+
+/// The cell holding the decomposed value and its little-endian bit cells.
+pub type Decomposition<F> = (AssignedCell<F, F>, Vec<AssignedCell<F, F>>);
+
+/// Decomposes `value` into `num_bits` boolean cells, little-endian. The
+/// weighted sum of the bits is constrained to equal `value`, so this also
+/// proves `value < 2^num_bits`.
+///
+/// `num_bits` must be at least 1 and below `F::NUM_BITS`, so that the sum
+/// cannot wrap around the modulus; otherwise this returns
+/// [`Error::Synthesis`].
+pub fn decompose_bits<F: PrimeField>(
+    config: &DecomposeConfig,
+    layouter: impl Layouter<F>,
+    value: Value<F>,
+    num_bits: usize,
+) -> Result<Vec<AssignedCell<F, F>>, Error> {
+    decompose(config, layouter, value, num_bits).map(|(_, bits)| bits)
+}
+
+/// Like [`decompose_bits`], but also returns the cell holding `value`.
+pub fn decompose<F: PrimeField>(
+    config: &DecomposeConfig,
+    mut layouter: impl Layouter<F>,
+    value: Value<F>,
+    num_bits: usize,
+) -> Result<Decomposition<F>, Error> {
+    // With no bits, row 0 would hold both `value` and the final zero.
+    if num_bits == 0 || num_bits >= F::NUM_BITS as usize {
+        return Err(Error::Synthesis);
+    }
+
+    layouter.assign_region(
+        || "decompose",
+        |mut region| {
+            let bit_values = value.map(|v| le_bits(&v, num_bits));
+
+            let value_cell = region.assign_advice(|| "value", config.acc, 0, || value)?;
+
+            config.q_end.enable(&mut region, num_bits)?;
+            let mut acc = Value::known(F::ZERO);
+            region.assign_advice(|| "acc", config.acc, num_bits, || acc)?;
+
+            let mut bits = Vec::with_capacity(num_bits);
+            for i in (0..num_bits).rev() {
+                config.q_bit.enable(&mut region, i)?;
+                let bit = bit_values.as_ref().map(|bits| bits[i]);
+                bits.push(region.assign_advice(|| "bit", config.bit, i, || bit)?);
+
+                acc = acc.zip(bit).map(|(acc, bit)| acc.double() + bit);
+                if i > 0 {
+                    region.assign_advice(|| "acc", config.acc, i, || acc)?;
+                }
+            }
+            bits.reverse();
+
+            Ok((value_cell, bits))
+        },
+    )
+}
+
+/// The lowest `num_bits` bits of `value`, little-endian. Assumes a
+/// little-endian `to_repr`, as for the pasta fields.
+fn le_bits<F: PrimeField>(value: &F, num_bits: usize) -> Vec<F> {
+    let repr = value.to_repr();
+    repr.as_ref()
+        .iter()
+        .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1))
+        .chain(std::iter::repeat(0))
+        .take(num_bits)
+        .map(|bit| F::from(u64::from(bit)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use halo2_proofs::{
+        circuit::SimpleFloorPlanner,
+        dev::MockProver,
+        pasta::Fp,
+        plonk::{Circuit, Instance},
+    };
+
+    struct DecomposeCircuit<F: PrimeField> {
+        value: Value<F>,
+        num_bits: usize,
+    }
+
+    impl<F: PrimeField> Circuit<F> for DecomposeCircuit<F> {
+        type Config = (DecomposeConfig, Column<Instance>);
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            DecomposeCircuit {
+                value: Value::unknown(),
+                num_bits: self.num_bits,
+            }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let acc = meta.advice_column();
+            let bit = meta.advice_column();
+            let instance = meta.instance_column();
+            meta.enable_equality(instance);
+
+            (DecomposeConfig::configure(meta, acc, bit), instance)
+        }
+
+        fn synthesize(
+            &self,
+            (config, instance): Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let bits = decompose_bits(
+                &config,
+                layouter.namespace(|| "decompose"),
+                self.value,
+                self.num_bits,
+            )?;
+            // Expose the bits so the test can check each cell's value.
+            for (i, bit) in bits.iter().enumerate() {
+                layouter.constrain_instance(bit.cell(), instance, i)?;
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn decompose_4_bits() {
+        let value = 0b1011;
+        let bits = [1u64, 1, 0, 1];
+
+        let circuit = DecomposeCircuit {
+            value: Value::known(Fp::from(value)),
+            num_bits: 4,
+        };
+        let public_inputs = bits.iter().map(|bit| Fp::from(*bit)).collect();
+        let prover = MockProver::run(4, &circuit, vec![public_inputs]).unwrap();
+        prover.assert_satisfied();
+
+        // Big-endian bits are rejected.
+        let public_inputs = bits.iter().rev().map(|bit| Fp::from(*bit)).collect();
+        let prover = MockProver::run(4, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn decompose_zero_bits() {
+        let circuit = DecomposeCircuit {
+            value: Value::known(Fp::from(5)),
+            num_bits: 0,
+        };
+        let result = MockProver::run(4, &circuit, vec![vec![]]);
+        assert!(matches!(result, Err(Error::Synthesis)));
+    }
+
+    #[test]
+    fn decompose_too_many_bits() {
+        let circuit = DecomposeCircuit {
+            value: Value::known(Fp::from(5)),
+            num_bits: Fp::NUM_BITS as usize,
+        };
+        let result = MockProver::run(4, &circuit, vec![vec![]]);
+        assert!(matches!(result, Err(Error::Synthesis)));
+    }
+
+    #[test]
+    fn decompose_overflow() {
+        // 0b11011 needs five bits.
+        let circuit = DecomposeCircuit {
+            value: Value::known(Fp::from(0b11011)),
+            num_bits: 4,
+        };
+        let public_inputs = [1u64, 1, 0, 1].iter().map(|bit| Fp::from(*bit)).collect();
+        let prover = MockProver::run(4, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+}
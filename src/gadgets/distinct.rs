@@ -0,0 +1,146 @@
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Layouter},
+    plonk::{Advice, Column, ConstraintSystem, Constraints, Error, Selector},
+    poly::Rotation,
+};
+
+use super::is_zero::{IsZeroChip, IsZeroConfig};
+
+/// Constrains `a != b` by requiring `is_zero(a - b) == 0`.
+///
+/// | a0 | a1 | value_inv     | is_zero | q_distinct |
+/// |----|----|---------------|---------|------------|
+/// | a  | b  | 1 / (a - b)   |    0    |     1      |
+#[derive(Clone, Debug)]
+pub struct DistinctConfig<F: Field> {
+    pub advice: [Column<Advice>; 2],
+    pub q_distinct: Selector,
+    pub is_zero: IsZeroConfig<F>,
+}
+
+impl<F: Field> DistinctConfig<F> {
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        advice: [Column<Advice>; 2],
+        value_inv: Column<Advice>,
+    ) -> Self {
+        for column in &advice {
+            meta.enable_equality(*column);
+        }
+        let q_distinct = meta.selector();
+
+        let is_zero = IsZeroChip::configure(
+            meta,
+            |meta| meta.query_selector(q_distinct),
+            |meta| {
+                meta.query_advice(advice[0], Rotation::cur())
+                    - meta.query_advice(advice[1], Rotation::cur())
+            },
+            value_inv,
+        );
+
+        meta.create_gate("distinct", |meta| {
+            let q_distinct = meta.query_selector(q_distinct);
+            let is_zero = meta.query_advice(is_zero.is_zero, Rotation::cur());
+
+            Constraints::with_selector(q_distinct, [("a != b", is_zero)])
+        });
+
+        DistinctConfig {
+            advice,
+            q_distinct,
+            is_zero,
+        }
+    }
+}
+
+pub fn assert_distinct<F: Field>(
+    config: &DistinctConfig<F>,
+    mut layouter: impl Layouter<F>,
+    a: &AssignedCell<F, F>,
+    b: &AssignedCell<F, F>,
+) -> Result<(), Error> {
+    let is_zero = IsZeroChip::construct(config.is_zero.clone());
+    layouter.assign_region(
+        || "assert distinct",
+        |mut region| {
+            config.q_distinct.enable(&mut region, 0)?;
+            let a = a.copy_advice(|| "a", &mut region, config.advice[0], 0)?;
+            let b = b.copy_advice(|| "b", &mut region, config.advice[1], 0)?;
+
+            let diff = a.value().copied() - b.value();
+            is_zero.assign(&mut region, 0, diff)?;
+            Ok(())
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::assert_fails_with;
+    use halo2_proofs::{
+        circuit::{SimpleFloorPlanner, Value},
+        dev::MockProver,
+        pasta::Fp,
+        plonk::Circuit,
+    };
+
+    #[derive(Default)]
+    struct DistinctCircuit<F: Field> {
+        a: Value<F>,
+        b: Value<F>,
+    }
+
+    impl<F: Field> Circuit<F> for DistinctCircuit<F> {
+        type Config = DistinctConfig<F>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let advice = [meta.advice_column(), meta.advice_column()];
+            let value_inv = meta.advice_column();
+            DistinctConfig::configure(meta, advice, value_inv)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let (a, b) = layouter.assign_region(
+                || "load inputs",
+                |mut region| {
+                    let a = region.assign_advice(|| "a", config.advice[0], 0, || self.a)?;
+                    let b = region.assign_advice(|| "b", config.advice[1], 0, || self.b)?;
+                    Ok((a, b))
+                },
+            )?;
+
+            assert_distinct(&config, layouter.namespace(|| "a != b"), &a, &b)
+        }
+    }
+
+    fn circuit(a: u64, b: u64) -> DistinctCircuit<Fp> {
+        DistinctCircuit {
+            a: Value::known(Fp::from(a)),
+            b: Value::known(Fp::from(b)),
+        }
+    }
+
+    #[test]
+    fn distinct_cells() {
+        let prover = MockProver::run(4, &circuit(3, 4), vec![]).unwrap();
+        prover.assert_satisfied();
+    }
+
+    #[test]
+    fn equal_cells() {
+        let prover = MockProver::run(4, &circuit(3, 3), vec![]).unwrap();
+        assert_fails_with(&prover, "a != b");
+    }
+}
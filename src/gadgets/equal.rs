@@ -0,0 +1,124 @@
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Layouter},
+    plonk::{Advice, Column, ConstraintSystem, Constraints, Error, Selector},
+    poly::Rotation,
+};
+
+/// | a0 | a1 | q_equal |
+/// |----|----|---------|
+/// | a  | b  |    1    |
+#[derive(Clone, Debug)]
+pub struct EqualConfig {
+    pub advice: [Column<Advice>; 2],
+    pub q_equal: Selector,
+}
+
+impl EqualConfig {
+    pub fn configure<F: Field>(
+        meta: &mut ConstraintSystem<F>,
+        advice: [Column<Advice>; 2],
+    ) -> Self {
+        for column in &advice {
+            meta.enable_equality(*column);
+        }
+        let q_equal = meta.selector();
+
+        meta.create_gate("equal", |meta| {
+            let q_equal = meta.query_selector(q_equal);
+            let a = meta.query_advice(advice[0], Rotation::cur());
+            let b = meta.query_advice(advice[1], Rotation::cur());
+
+            Constraints::with_selector(q_equal, [("a = b", a - b)])
+        });
+
+        EqualConfig { advice, q_equal }
+    }
+}
+
+/// Constrains `a == b` with an explicit one-row gate, so the equality
+/// shows up as a named constraint instead of only a permutation copy.
+pub fn constrain_equal<F: Field>(
+    config: &EqualConfig,
+    mut layouter: impl Layouter<F>,
+    a: &AssignedCell<F, F>,
+    b: &AssignedCell<F, F>,
+) -> Result<(), Error> {
+    layouter.assign_region(
+        || "constrain equal",
+        |mut region| {
+            config.q_equal.enable(&mut region, 0)?;
+            a.copy_advice(|| "a", &mut region, config.advice[0], 0)?;
+            b.copy_advice(|| "b", &mut region, config.advice[1], 0)?;
+            Ok(())
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::assert_fails_with;
+    use halo2_proofs::{
+        circuit::{SimpleFloorPlanner, Value},
+        dev::MockProver,
+        pasta::Fp,
+        plonk::Circuit,
+    };
+
+    #[derive(Default)]
+    struct EqualCircuit<F: Field> {
+        a: Value<F>,
+        b: Value<F>,
+    }
+
+    impl<F: Field> Circuit<F> for EqualCircuit<F> {
+        type Config = EqualConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let advice = [meta.advice_column(), meta.advice_column()];
+            EqualConfig::configure(meta, advice)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let (a, b) = layouter.assign_region(
+                || "load inputs",
+                |mut region| {
+                    let a = region.assign_advice(|| "a", config.advice[0], 0, || self.a)?;
+                    let b = region.assign_advice(|| "b", config.advice[1], 0, || self.b)?;
+                    Ok((a, b))
+                },
+            )?;
+
+            constrain_equal(&config, layouter.namespace(|| "a = b"), &a, &b)
+        }
+    }
+
+    fn circuit(a: u64, b: u64) -> EqualCircuit<Fp> {
+        EqualCircuit {
+            a: Value::known(Fp::from(a)),
+            b: Value::known(Fp::from(b)),
+        }
+    }
+
+    #[test]
+    fn equal_cells() {
+        let prover = MockProver::run(4, &circuit(5, 5), vec![]).unwrap();
+        prover.assert_satisfied();
+    }
+
+    #[test]
+    fn different_cells() {
+        let prover = MockProver::run(4, &circuit(5, 6), vec![]).unwrap();
+        assert_fails_with(&prover, "a = b");
+    }
+}
@@ -0,0 +1,192 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Region, Value},
+    plonk::{Advice, Column, ConstraintSystem, Constraints, Error, Expression, VirtualCells},
+    poly::Rotation,
+};
+
+/// Witnesses `is_zero = 1` iff `value == 0`, using the inverse of `value`:
+///
+/// | value | value_inv | is_zero | q_enable |
+/// |-------|-----------|---------|----------|
+/// |   v   |   1 / v   |    0    |    1     |
+/// |   0   |     0     |    1    |    1     |
+///
+/// - `value * value_inv == 1 - is_zero`
+/// - `value * is_zero == 0`
+///
+/// `value` is an expression rather than a column, so the chip can be
+/// embedded in another gate's row. The owner of that row places `value`,
+/// enables `q_enable`, and then calls [`IsZeroChip::assign`] on the same
+/// region and offset; the returned cell can drive a swap or select elsewhere
+/// in the circuit.
+#[derive(Clone, Debug)]
+pub struct IsZeroConfig<F: Field> {
+    pub value_inv: Column<Advice>,
+    pub is_zero: Column<Advice>,
+    _marker: PhantomData<F>,
+}
+
+#[derive(Clone, Debug)]
+pub struct IsZeroChip<F: Field> {
+    config: IsZeroConfig<F>,
+}
+
+impl<F: Field> IsZeroChip<F> {
+    pub fn construct(config: IsZeroConfig<F>) -> Self {
+        Self { config }
+    }
+
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        q_enable: impl FnOnce(&mut VirtualCells<'_, F>) -> Expression<F>,
+        value: impl FnOnce(&mut VirtualCells<'_, F>) -> Expression<F>,
+        value_inv: Column<Advice>,
+    ) -> IsZeroConfig<F> {
+        let is_zero = meta.advice_column();
+        meta.enable_equality(is_zero);
+
+        meta.create_gate("is zero", |meta| {
+            let q_enable = q_enable(meta);
+            let value = value(meta);
+            let value_inv = meta.query_advice(value_inv, Rotation::cur());
+            let is_zero = meta.query_advice(is_zero, Rotation::cur());
+            let one = Expression::Constant(F::ONE);
+
+            Constraints::with_selector(
+                q_enable,
+                [
+                    (
+                        "value * value_inv = 1 - is_zero",
+                        value.clone() * value_inv - (one - is_zero.clone()),
+                    ),
+                    ("value * is_zero = 0", value * is_zero),
+                ],
+            )
+        });
+
+        IsZeroConfig {
+            value_inv,
+            is_zero,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Assigns `value_inv` and `is_zero` at `offset` and returns the
+    /// `is_zero` cell.
+    pub fn assign(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: usize,
+        value: Value<F>,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        let value_inv = value.map(|v| v.invert().unwrap_or(F::ZERO));
+        region.assign_advice(|| "value_inv", self.config.value_inv, offset, || value_inv)?;
+
+        let is_zero = value.map(|v| {
+            if bool::from(v.is_zero()) {
+                F::ONE
+            } else {
+                F::ZERO
+            }
+        });
+        region.assign_advice(|| "is_zero", self.config.is_zero, offset, || is_zero)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use halo2_proofs::{
+        circuit::{Layouter, SimpleFloorPlanner},
+        dev::MockProver,
+        pasta::Fp,
+        plonk::{Circuit, Instance, Selector},
+    };
+
+    #[derive(Clone, Debug)]
+    struct TestConfig<F: Field> {
+        value: Column<Advice>,
+        q_enable: Selector,
+        is_zero: IsZeroConfig<F>,
+        instance: Column<Instance>,
+    }
+
+    #[derive(Default)]
+    struct IsZeroCircuit<F: Field> {
+        value: Value<F>,
+    }
+
+    impl<F: Field> Circuit<F> for IsZeroCircuit<F> {
+        type Config = TestConfig<F>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let value = meta.advice_column();
+            let value_inv = meta.advice_column();
+            let q_enable = meta.selector();
+            let instance = meta.instance_column();
+            meta.enable_equality(instance);
+
+            let is_zero = IsZeroChip::configure(
+                meta,
+                |meta| meta.query_selector(q_enable),
+                |meta| meta.query_advice(value, Rotation::cur()),
+                value_inv,
+            );
+
+            TestConfig {
+                value,
+                q_enable,
+                is_zero,
+                instance,
+            }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let chip = IsZeroChip::construct(config.is_zero.clone());
+            let is_zero = layouter.assign_region(
+                || "is zero",
+                |mut region| {
+                    config.q_enable.enable(&mut region, 0)?;
+                    region.assign_advice(|| "value", config.value, 0, || self.value)?;
+                    chip.assign(&mut region, 0, self.value)
+                },
+            )?;
+            layouter.constrain_instance(is_zero.cell(), config.instance, 0)
+        }
+    }
+
+    fn check(value: Fp, expected: Fp) {
+        let circuit = IsZeroCircuit {
+            value: Value::known(value),
+        };
+        let prover = MockProver::run(4, &circuit, vec![vec![expected]]).unwrap();
+        prover.assert_satisfied();
+
+        let prover = MockProver::run(4, &circuit, vec![vec![Fp::one() - expected]]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn is_zero_on_zero() {
+        check(Fp::zero(), Fp::one());
+    }
+
+    #[test]
+    fn is_zero_on_non_zero() {
+        for value in [Fp::one(), Fp::from(5), -Fp::one()] {
+            check(value, Fp::zero());
+        }
+    }
+}
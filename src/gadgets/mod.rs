@@ -0,0 +1,12 @@
+pub mod arithmetic;
+pub mod cmp;
+pub mod decompose;
+pub mod distinct;
+pub mod equal;
+pub mod is_zero;
+pub mod range_check;
+pub mod select;
+pub mod table;
+pub mod table2;
+
+pub use equal::{constrain_equal, EqualConfig};
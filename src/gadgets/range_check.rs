@@ -0,0 +1,400 @@
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, Value},
+    pasta::group::ff::PrimeField,
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
+};
+
+use super::{
+    decompose::{decompose, DecomposeConfig},
+    table::{LoadableTable, LookupTable},
+    table2::RangeCheckTable,
+};
+
+/// Checks that a witnessed value lies in `[0, RANGE)` (or in a union of
+/// ranges) with a single-column lookup.
+///
+/// | value | q_lookup |  table  |
+/// |-------|----------|---------|
+/// |  v_0  |    1     |    0    |
+/// |       |          |    1    |
+/// |       |          |   ...   |
+/// |       |          | RANGE-1 |
+///
+/// [`RangeCheckChip::assign_lt`] instead decomposes the value into bits,
+/// reusing `value` as the running sum of a [`DecomposeConfig`]. The
+/// decomposition is only configured by
+/// [`RangeCheckChip::configure_with_runtime_bits`], so plain lookup users do
+/// not pay for its column and gates.
+#[derive(Clone, Debug)]
+pub struct RangeCheckConfig<F: PrimeField, const RANGE: usize> {
+    pub value: Column<Advice>,
+    pub q_lookup: Selector,
+    pub table: LookupTable<F, RANGE>,
+    pub decompose: Option<DecomposeConfig>,
+}
+
+#[derive(Clone, Debug)]
+pub struct RangeCheckChip<F: PrimeField, const RANGE: usize> {
+    config: RangeCheckConfig<F, RANGE>,
+}
+
+impl<F: PrimeField, const RANGE: usize> RangeCheckChip<F, RANGE> {
+    pub fn construct(config: RangeCheckConfig<F, RANGE>) -> Self {
+        Self { config }
+    }
+
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        value: Column<Advice>,
+    ) -> RangeCheckConfig<F, RANGE> {
+        Self::configure_ranges(meta, value, &[(0, RANGE - 1)])
+    }
+
+    /// Configures the check against the union of several disjoint, inclusive
+    /// ranges below `RANGE`, e.g. `[a,b] ∪ [c,d]`.
+    pub fn configure_ranges(
+        meta: &mut ConstraintSystem<F>,
+        value: Column<Advice>,
+        ranges: &[(usize, usize)],
+    ) -> RangeCheckConfig<F, RANGE> {
+        let q_lookup = meta.complex_selector();
+        let table = LookupTable::<F, RANGE>::configure_ranges(meta, ranges);
+
+        meta.enable_equality(value);
+
+        // Zero is not necessarily part of the table, so rows without the
+        // selector look up the first allowed value instead.
+        let default = Expression::Constant(F::from(ranges[0].0 as u64));
+        meta.lookup(|meta| {
+            let q_lookup = meta.query_selector(q_lookup);
+            let non_q = Expression::Constant(F::ONE) - q_lookup.clone();
+            let v = meta.query_advice(value, Rotation::cur());
+            vec![(q_lookup * v + non_q * default, table.table)]
+        });
+
+        RangeCheckConfig {
+            value,
+            q_lookup,
+            table,
+            decompose: None,
+        }
+    }
+
+    /// Like [`Self::configure`], but also allocates a bit column and the
+    /// decomposition gates needed by [`Self::assign_lt`].
+    pub fn configure_with_runtime_bits(
+        meta: &mut ConstraintSystem<F>,
+        value: Column<Advice>,
+    ) -> RangeCheckConfig<F, RANGE> {
+        let mut config = Self::configure(meta, value);
+        let bit = meta.advice_column();
+        config.decompose = Some(DecomposeConfig::configure(meta, value, bit));
+        config
+    }
+
+    pub fn load_table(&self, layouter: impl Layouter<F>) -> Result<(), Error> {
+        self.config.table.load(layouter)
+    }
+
+    /// Assigns `value` and enables the lookup on it.
+    pub fn assign(
+        &self,
+        mut layouter: impl Layouter<F>,
+        value: Value<F>,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        layouter.assign_region(
+            || "range check",
+            |mut region| {
+                self.config.q_lookup.enable(&mut region, 0)?;
+                region.assign_advice(|| "value", self.config.value, 0, || value)
+            },
+        )
+    }
+
+    /// Checks `value < 2^num_bits` by decomposing it into `num_bits` boolean
+    /// cells, so the bound is chosen at synthesis time instead of via `RANGE`.
+    /// Returns the checked value's cell.
+    ///
+    /// Fails with [`Error::Synthesis`] unless the chip was configured with
+    /// [`Self::configure_with_runtime_bits`].
+    pub fn assign_lt(
+        &self,
+        layouter: impl Layouter<F>,
+        value: Value<F>,
+        num_bits: usize,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        let config = self.config.decompose.as_ref().ok_or(Error::Synthesis)?;
+        decompose(config, layouter, value, num_bits).map(|(value, _)| value)
+    }
+}
+
+/// Checks that a witnessed value has exactly `n_bits` bits by looking up the
+/// `(n_bits, value)` pair. Zero is tagged with one bit.
+///
+/// | value | n_bits | q_lookup | table_n_bits | table_value |
+/// |-------|--------|----------|--------------|-------------|
+/// |  v_0  |  b_0   |    1     |      1       |      0      |
+/// |       |        |          |      1       |      1      |
+/// |       |        |          |      2       |      2      |
+/// |       |        |          |      2       |      3      |
+/// |       |        |          |     ...      |     ...     |
+#[derive(Clone, Debug)]
+pub struct TaggedRangeCheckConfig<F: PrimeField, const NUM_BITS: usize, const RANGE: usize> {
+    pub value: Column<Advice>,
+    pub n_bits: Column<Advice>,
+    pub q_lookup: Selector,
+    pub table: RangeCheckTable<F, NUM_BITS, RANGE>,
+}
+
+#[derive(Clone, Debug)]
+pub struct TaggedRangeCheckChip<F: PrimeField, const NUM_BITS: usize, const RANGE: usize> {
+    config: TaggedRangeCheckConfig<F, NUM_BITS, RANGE>,
+}
+
+impl<F: PrimeField, const NUM_BITS: usize, const RANGE: usize>
+    TaggedRangeCheckChip<F, NUM_BITS, RANGE>
+{
+    pub fn construct(config: TaggedRangeCheckConfig<F, NUM_BITS, RANGE>) -> Self {
+        Self { config }
+    }
+
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        value: Column<Advice>,
+        n_bits: Column<Advice>,
+    ) -> TaggedRangeCheckConfig<F, NUM_BITS, RANGE> {
+        let q_lookup = meta.complex_selector();
+        let table = RangeCheckTable::<F, NUM_BITS, RANGE>::configure(meta);
+
+        meta.enable_equality(value);
+
+        meta.lookup(|meta| {
+            // (1, 0) is the first table row, so it is used for disabled rows.
+            let default_value = Expression::Constant(F::ZERO);
+            let default_bit = Expression::Constant(F::ONE);
+            let value = meta.query_advice(value, Rotation::cur());
+            let n_bits = meta.query_advice(n_bits, Rotation::cur());
+            let q_lookup = meta.query_selector(q_lookup);
+            let non_q = Expression::Constant(F::ONE) - q_lookup.clone();
+
+            let v = value * q_lookup.clone() + non_q.clone() * default_value;
+            let b = n_bits * q_lookup + non_q * default_bit;
+            vec![(b, table.n_bits), (v, table.value)]
+        });
+
+        TaggedRangeCheckConfig {
+            value,
+            n_bits,
+            q_lookup,
+            table,
+        }
+    }
+
+    pub fn load_table(&self, layouter: impl Layouter<F>) -> Result<(), Error> {
+        self.config.table.load(layouter)
+    }
+
+    /// Assigns `value` tagged with `num_bits` and enables the lookup on it.
+    pub fn assign(
+        &self,
+        mut layouter: impl Layouter<F>,
+        value: Value<F>,
+        num_bits: usize,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        layouter.assign_region(
+            || "tagged range check",
+            |mut region| {
+                self.config.q_lookup.enable(&mut region, 0)?;
+                region.assign_advice(
+                    || "n_bits",
+                    self.config.n_bits,
+                    0,
+                    || Value::known(F::from(num_bits as u64)),
+                )?;
+                region.assign_advice(|| "value", self.config.value, 0, || value)
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use halo2_proofs::{
+        circuit::SimpleFloorPlanner,
+        dev::MockProver,
+        pasta::Fp,
+        plonk::{Circuit, Instance},
+    };
+
+    #[derive(Default)]
+    struct PlainCircuit<F: PrimeField> {
+        values: Vec<Value<F>>,
+    }
+
+    impl<F: PrimeField> Circuit<F> for PlainCircuit<F> {
+        type Config = RangeCheckConfig<F, 16>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let value = meta.advice_column();
+            RangeCheckChip::configure(meta, value)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let chip = RangeCheckChip::construct(config);
+            chip.load_table(layouter.namespace(|| "table"))?;
+            for value in &self.values {
+                chip.assign(layouter.namespace(|| "value"), *value)?;
+            }
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct TaggedCircuit<F: PrimeField> {
+        values: Vec<(Value<F>, usize)>,
+    }
+
+    impl<F: PrimeField> Circuit<F> for TaggedCircuit<F> {
+        type Config = TaggedRangeCheckConfig<F, 4, 16>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let value = meta.advice_column();
+            let n_bits = meta.advice_column();
+            TaggedRangeCheckChip::configure(meta, value, n_bits)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let chip = TaggedRangeCheckChip::construct(config);
+            chip.load_table(layouter.namespace(|| "table"))?;
+            for (value, num_bits) in &self.values {
+                chip.assign(layouter.namespace(|| "value"), *value, *num_bits)?;
+            }
+            Ok(())
+        }
+    }
+
+    struct LtCircuit<F: PrimeField> {
+        value: Value<F>,
+        num_bits: usize,
+    }
+
+    impl<F: PrimeField> Circuit<F> for LtCircuit<F> {
+        type Config = (RangeCheckConfig<F, 16>, Column<Instance>);
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            LtCircuit {
+                value: Value::unknown(),
+                num_bits: self.num_bits,
+            }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let value = meta.advice_column();
+            let instance = meta.instance_column();
+            meta.enable_equality(instance);
+
+            (
+                RangeCheckChip::configure_with_runtime_bits(meta, value),
+                instance,
+            )
+        }
+
+        fn synthesize(
+            &self,
+            (config, instance): Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let chip = RangeCheckChip::construct(config);
+            chip.load_table(layouter.namespace(|| "table"))?;
+            let value =
+                chip.assign_lt(layouter.namespace(|| "value"), self.value, self.num_bits)?;
+            layouter.constrain_instance(value.cell(), instance, 0)
+        }
+    }
+
+    fn plain(values: &[u64]) -> PlainCircuit<Fp> {
+        PlainCircuit {
+            values: values.iter().map(|v| Value::known(Fp::from(*v))).collect(),
+        }
+    }
+
+    fn tagged(values: &[(u64, usize)]) -> TaggedCircuit<Fp> {
+        TaggedCircuit {
+            values: values
+                .iter()
+                .map(|(v, n)| (Value::known(Fp::from(*v)), *n))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn range_check_in_range() {
+        let prover = MockProver::run(5, &plain(&[0, 7, 15]), vec![]).unwrap();
+        prover.assert_satisfied();
+    }
+
+    #[test]
+    fn range_check_out_of_range() {
+        let prover = MockProver::run(5, &plain(&[3, 16]), vec![]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn tagged_range_check_in_range() {
+        let prover = MockProver::run(5, &tagged(&[(0, 1), (5, 3), (15, 4)]), vec![]).unwrap();
+        prover.assert_satisfied();
+    }
+
+    #[test]
+    fn tagged_range_check_out_of_range() {
+        // 5 has three bits, not four.
+        let prover = MockProver::run(5, &tagged(&[(5, 4)]), vec![]).unwrap();
+        assert!(prover.verify().is_err());
+
+        // 16 does not fit in the 4-bit table at all.
+        let prover = MockProver::run(5, &tagged(&[(16, 4)]), vec![]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn range_check_runtime_bits() {
+        let circuit = LtCircuit {
+            value: Value::known(Fp::from(13)),
+            num_bits: 4,
+        };
+        let prover = MockProver::run(5, &circuit, vec![vec![Fp::from(13)]]).unwrap();
+        prover.assert_satisfied();
+
+        // The returned cell holds the checked value.
+        let prover = MockProver::run(5, &circuit, vec![vec![Fp::from(14)]]).unwrap();
+        assert!(prover.verify().is_err());
+
+        let circuit = LtCircuit {
+            value: Value::known(Fp::from(20)),
+            num_bits: 4,
+        };
+        let prover = MockProver::run(5, &circuit, vec![vec![Fp::from(20)]]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+}
@@ -0,0 +1,164 @@
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Layouter},
+    plonk::{Advice, Column, ConstraintSystem, Constraints, Error, Expression, Selector},
+    poly::Rotation,
+};
+
+/// `out = cond * a + (1 - cond) * b` with `cond` boolean.
+///
+/// | a0   | a1 | a2 | q_select |
+/// |------|----|----|----------|
+/// | cond | a  | b  |    1     |
+/// | out  |    |    |    0     |
+#[derive(Clone, Debug)]
+pub struct SelectConfig {
+    pub advice: [Column<Advice>; 3],
+    pub q_select: Selector,
+}
+
+impl SelectConfig {
+    pub fn configure<F: Field>(
+        meta: &mut ConstraintSystem<F>,
+        advice: [Column<Advice>; 3],
+    ) -> Self {
+        for column in &advice {
+            meta.enable_equality(*column);
+        }
+        let q_select = meta.selector();
+
+        meta.create_gate("select", |meta| {
+            let q_select = meta.query_selector(q_select);
+            let cond = meta.query_advice(advice[0], Rotation::cur());
+            let a = meta.query_advice(advice[1], Rotation::cur());
+            let b = meta.query_advice(advice[2], Rotation::cur());
+            let out = meta.query_advice(advice[0], Rotation::next());
+            let one = Expression::Constant(F::ONE);
+
+            Constraints::with_selector(
+                q_select,
+                [
+                    ("cond is boolean", cond.clone() * (one - cond.clone())),
+                    (
+                        "out = cond * a + (1 - cond) * b",
+                        b.clone() + cond * (a - b) - out,
+                    ),
+                ],
+            )
+        });
+
+        SelectConfig { advice, q_select }
+    }
+}
+
+/// Returns a cell equal to `a` when `cond == 1` and to `b` when `cond == 0`.
+pub fn conditional_select<F: Field>(
+    config: &SelectConfig,
+    mut layouter: impl Layouter<F>,
+    cond: &AssignedCell<F, F>,
+    a: &AssignedCell<F, F>,
+    b: &AssignedCell<F, F>,
+) -> Result<AssignedCell<F, F>, Error> {
+    layouter.assign_region(
+        || "select",
+        |mut region| {
+            config.q_select.enable(&mut region, 0)?;
+            let cond = cond.copy_advice(|| "cond", &mut region, config.advice[0], 0)?;
+            let a = a.copy_advice(|| "a", &mut region, config.advice[1], 0)?;
+            let b = b.copy_advice(|| "b", &mut region, config.advice[2], 0)?;
+
+            let value = cond
+                .value()
+                .zip(a.value())
+                .zip(b.value())
+                .map(|((cond, a), b)| *b + *cond * (*a - *b));
+            region.assign_advice(|| "out", config.advice[0], 1, || value)
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::assert_fails_with;
+    use halo2_proofs::{
+        circuit::{SimpleFloorPlanner, Value},
+        dev::MockProver,
+        pasta::Fp,
+        plonk::{Circuit, Instance},
+    };
+
+    #[derive(Default)]
+    struct SelectCircuit<F: Field> {
+        cond: Value<F>,
+        a: Value<F>,
+        b: Value<F>,
+    }
+
+    impl<F: Field> Circuit<F> for SelectCircuit<F> {
+        type Config = (SelectConfig, Column<Instance>);
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let advice = [
+                meta.advice_column(),
+                meta.advice_column(),
+                meta.advice_column(),
+            ];
+            let instance = meta.instance_column();
+            meta.enable_equality(instance);
+
+            (SelectConfig::configure(meta, advice), instance)
+        }
+
+        fn synthesize(
+            &self,
+            (config, instance): Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let (cond, a, b) = layouter.assign_region(
+                || "load inputs",
+                |mut region| {
+                    let cond =
+                        region.assign_advice(|| "cond", config.advice[0], 0, || self.cond)?;
+                    let a = region.assign_advice(|| "a", config.advice[1], 0, || self.a)?;
+                    let b = region.assign_advice(|| "b", config.advice[2], 0, || self.b)?;
+                    Ok((cond, a, b))
+                },
+            )?;
+
+            let out = conditional_select(&config, layouter.namespace(|| "select"), &cond, &a, &b)?;
+            layouter.constrain_instance(out.cell(), instance, 0)
+        }
+    }
+
+    fn circuit(cond: u64) -> SelectCircuit<Fp> {
+        SelectCircuit {
+            cond: Value::known(Fp::from(cond)),
+            a: Value::known(Fp::from(3)),
+            b: Value::known(Fp::from(7)),
+        }
+    }
+
+    #[test]
+    fn select_a_or_b() {
+        let prover = MockProver::run(4, &circuit(1), vec![vec![Fp::from(3)]]).unwrap();
+        prover.assert_satisfied();
+
+        let prover = MockProver::run(4, &circuit(0), vec![vec![Fp::from(7)]]).unwrap();
+        prover.assert_satisfied();
+    }
+
+    #[test]
+    fn select_non_boolean_cond() {
+        // out = 7 + 2 * (3 - 7) satisfies the select equation but not cond's
+        // booleanity.
+        let out = Fp::from(7) - Fp::from(8);
+        let prover = MockProver::run(4, &circuit(2), vec![vec![out]]).unwrap();
+        assert_fails_with(&prover, "cond is boolean");
+    }
+}
@@ -0,0 +1,227 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{circuit::*, pasta::group::ff::PrimeField, plonk::*};
+
+/// A lookup table that can assign its own contents.
+pub trait LoadableTable<F: PrimeField> {
+    /// Assigns the table's rows.
+    ///
+    /// A layouter does not expose `k`, so a table with more rows than `k`
+    /// leaves usable is caught when its cells are assigned: the prover
+    /// returns [`Error::NotEnoughRowsAvailable`] from here.
+    fn load(&self, layouter: impl Layouter<F>) -> Result<(), Error>;
+}
+
+/// Loads both tables, one after the other.
+impl<F: PrimeField, A: LoadableTable<F>, B: LoadableTable<F>> LoadableTable<F> for (A, B) {
+    fn load(&self, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+        self.0.load(layouter.namespace(|| "table 0"))?;
+        self.1.load(layouter.namespace(|| "table 1"))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LookupTable<F: PrimeField, const RANGE: usize> {
+    pub table: TableColumn,
+    /// Inclusive `(start, end)` ranges loaded into the table, in order.
+    pub ranges: Vec<(usize, usize)>,
+    _marker: PhantomData<F>,
+}
+
+impl<F: PrimeField, const RANGE: usize> LookupTable<F, RANGE> {
+    pub fn configure(meta: &mut ConstraintSystem<F>) -> Self {
+        assert!(RANGE > 0, "RANGE must not be zero");
+        Self::configure_ranges(meta, &[(0, RANGE - 1)])
+    }
+
+    /// Configures a table holding the union of the given inclusive ranges.
+    ///
+    /// The ranges must be non-empty, sorted, disjoint and below `RANGE`.
+    pub fn configure_ranges(meta: &mut ConstraintSystem<F>, ranges: &[(usize, usize)]) -> Self {
+        assert!(!ranges.is_empty(), "at least one range is required");
+        for (i, &(start, end)) in ranges.iter().enumerate() {
+            assert!(start <= end, "range start must not exceed its end");
+            assert!(end < RANGE, "ranges must lie below RANGE");
+            if i > 0 {
+                assert!(
+                    ranges[i - 1].1 < start,
+                    "ranges must be sorted and disjoint"
+                );
+            }
+        }
+
+        let table = meta.lookup_table_column();
+        LookupTable {
+            table,
+            ranges: ranges.to_vec(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Number of table rows [`LoadableTable::load`] assigns.
+    pub fn rows(&self) -> usize {
+        self.ranges.iter().map(|(start, end)| end - start + 1).sum()
+    }
+}
+
+impl<F: PrimeField, const RANGE: usize> LoadableTable<F> for LookupTable<F, RANGE> {
+    fn load(&self, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+        layouter.assign_table(
+            || "table",
+            |mut table| {
+                let mut offset = 0;
+                for &(start, end) in &self.ranges {
+                    for i in start..=end {
+                        table.assign_cell(
+                            || "table",
+                            self.table,
+                            offset,
+                            || Value::known(F::from(i as u64)),
+                        )?;
+                        offset += 1;
+                    }
+                }
+                Ok(())
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadgets::table2::RangeCheckTable;
+    use halo2_proofs::{dev::MockProver, pasta::Fp, poly::Rotation};
+
+    #[derive(Clone, Debug)]
+    struct TestConfig<F: PrimeField> {
+        value: Column<Advice>,
+        q_lookup: Selector,
+        tables: (LookupTable<F, 8>, RangeCheckTable<F, 3, 8>),
+    }
+
+    /// Loads any table through the trait rather than an inherent method.
+    fn load_table<F: PrimeField, T: LoadableTable<F>>(
+        table: &T,
+        layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        table.load(layouter)
+    }
+
+    #[derive(Default)]
+    struct TablesCircuit<F: PrimeField> {
+        value: Value<F>,
+    }
+
+    impl<F: PrimeField> Circuit<F> for TablesCircuit<F> {
+        type Config = TestConfig<F>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let value = meta.advice_column();
+            let q_lookup = meta.complex_selector();
+            let tables = (
+                LookupTable::configure(meta),
+                RangeCheckTable::configure(meta),
+            );
+
+            meta.lookup(|meta| {
+                let q_lookup = meta.query_selector(q_lookup);
+                let value = meta.query_advice(value, Rotation::cur());
+                vec![(q_lookup * value, tables.0.table)]
+            });
+
+            TestConfig {
+                value,
+                q_lookup,
+                tables,
+            }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            load_table(&config.tables, layouter.namespace(|| "tables"))?;
+
+            layouter.assign_region(
+                || "value",
+                |mut region| {
+                    config.q_lookup.enable(&mut region, 0)?;
+                    region.assign_advice(|| "value", config.value, 0, || self.value)?;
+                    Ok(())
+                },
+            )
+        }
+    }
+
+    #[test]
+    fn load_through_trait() {
+        let circuit = TablesCircuit {
+            value: Value::known(Fp::from(5)),
+        };
+        let prover = MockProver::run(4, &circuit, vec![]).unwrap();
+        prover.assert_satisfied();
+
+        let circuit = TablesCircuit {
+            value: Value::known(Fp::from(9)),
+        };
+        let prover = MockProver::run(4, &circuit, vec![]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    struct RangeCircuit<const RANGE: usize>;
+
+    impl<F: PrimeField, const RANGE: usize> Circuit<F> for RangeCircuit<RANGE> {
+        type Config = LookupTable<F, RANGE>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            RangeCircuit
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            LookupTable::configure(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            config.load(layouter)
+        }
+    }
+
+    #[test]
+    fn rows_matches_load() {
+        let mut meta = ConstraintSystem::<Fp>::default();
+        let table = LookupTable::<Fp, 16>::configure_ranges(&mut meta, &[(2, 5), (10, 13)]);
+        assert_eq!(table.rows(), 8);
+
+        // k = 4 leaves 16 - 6 = 10 usable rows, and the floor planner pads the
+        // table from the first unused one, so exactly 9 rows fit.
+        let table = LookupTable::<Fp, 9>::configure(&mut meta);
+        assert_eq!(table.rows(), 9);
+        let prover = MockProver::<Fp>::run(4, &RangeCircuit::<9>, vec![]).unwrap();
+        prover.assert_satisfied();
+
+        let result = MockProver::<Fp>::run(4, &RangeCircuit::<10>, vec![]);
+        assert!(matches!(result, Err(Error::NotEnoughRowsAvailable { .. })));
+    }
+
+    #[test]
+    fn range_too_large_for_k() {
+        let prover = MockProver::<Fp>::run(5, &RangeCircuit::<16>, vec![]).unwrap();
+        prover.assert_satisfied();
+
+        // 2^5 rows, some of which are reserved for blinding.
+        let result = MockProver::<Fp>::run(5, &RangeCircuit::<32>, vec![]);
+        assert!(matches!(result, Err(Error::NotEnoughRowsAvailable { .. })));
+    }
+}
@@ -1,6 +1,8 @@
 use halo2_proofs::{circuit::*, pasta::group::ff::PrimeField, plonk::*};
 use std::marker::PhantomData;
 
+use super::table::LoadableTable;
+
 #[derive(Debug, Clone)]
 pub struct RangeCheckTable<F: PrimeField, const NUM_BITS: usize, const RANGE: usize> {
     pub n_bits: TableColumn,
@@ -19,7 +21,17 @@ impl<F: PrimeField, const NUM_BITS: usize, const RANGE: usize> RangeCheckTable<F
         }
     }
 
-    pub fn load(&self, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+    /// Number of table rows [`LoadableTable::load`] assigns: the `(1, 0)`
+    /// row plus `2^(n-1)` rows for each bit width `n`, i.e. `2^NUM_BITS`.
+    pub const fn rows() -> usize {
+        1 << NUM_BITS
+    }
+}
+
+impl<F: PrimeField, const NUM_BITS: usize, const RANGE: usize> LoadableTable<F>
+    for RangeCheckTable<F, NUM_BITS, RANGE>
+{
+    fn load(&self, mut layouter: impl Layouter<F>) -> Result<(), Error> {
         layouter.assign_table(
             || "table",
             |mut table| {
@@ -61,3 +73,48 @@ impl<F: PrimeField, const NUM_BITS: usize, const RANGE: usize> RangeCheckTable<F
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use halo2_proofs::{dev::MockProver, pasta::Fp};
+
+    struct TableCircuit<const NUM_BITS: usize>;
+
+    impl<F: PrimeField, const NUM_BITS: usize> Circuit<F> for TableCircuit<NUM_BITS> {
+        type Config = RangeCheckTable<F, NUM_BITS, 8>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            TableCircuit
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            RangeCheckTable::configure(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            config.load(layouter)
+        }
+    }
+
+    #[test]
+    fn rows_matches_load() {
+        let rows = 1 + (1..=3).map(|n| 1 << (n - 1)).sum::<usize>();
+        assert_eq!(RangeCheckTable::<Fp, 3, 8>::rows(), rows);
+
+        let prover = MockProver::<Fp>::run(4, &TableCircuit::<3>, vec![]).unwrap();
+        prover.assert_satisfied();
+    }
+
+    #[test]
+    fn table_too_large_for_k() {
+        // 2^5 table rows do not fit in the usable rows of k = 5.
+        let result = MockProver::<Fp>::run(5, &TableCircuit::<5>, vec![]);
+        assert!(matches!(result, Err(Error::NotEnoughRowsAvailable { .. })));
+    }
+}
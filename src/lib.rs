@@ -1 +1,5 @@
-pub mod examples;
\ No newline at end of file
+pub mod examples;
+pub mod gadgets;
+
+#[cfg(test)]
+mod testutil;
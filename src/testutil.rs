@@ -0,0 +1,34 @@
+use halo2_proofs::{
+    dev::{FailureLocation, MockProver, VerifyFailure},
+    pasta::Fp,
+};
+
+/// Asserts that `prover` fails verification with a failure named exactly
+/// `name`: an unsatisfied constraint whose gate or constraint has that name,
+/// or an unsatisfied lookup inside a region with that name.
+pub(crate) fn assert_fails_with(prover: &MockProver<Fp>, name: &str) {
+    let failures = prover
+        .verify()
+        .expect_err("expected the circuit to fail verification");
+
+    // The metadata types keep their names private but print each one as
+    // `('<name>')`, so comparing against that form is an exact match.
+    let quoted = format!("('{}')", name);
+    let matches = |failure: &VerifyFailure| match failure {
+        VerifyFailure::ConstraintNotSatisfied { constraint, .. } => {
+            constraint.to_string().contains(&quoted)
+        }
+        VerifyFailure::Lookup {
+            location: FailureLocation::InRegion { region, .. },
+            ..
+        } => region.to_string().ends_with(&quoted),
+        _ => false,
+    };
+
+    assert!(
+        failures.iter().any(matches),
+        "no failure is named `{}`: {:#?}",
+        name,
+        failures
+    );
+}